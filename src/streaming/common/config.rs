@@ -39,6 +39,29 @@ pub struct ConnectionConfig {
     pub request_timeout: u64,
     /// Maximum decoding message size in bytes (default: 10MB)
     pub max_decoding_message_size: usize,
+    /// Whether to resubscribe automatically when the gRPC stream ends (default: false)
+    pub auto_reconnect: bool,
+    /// Delay between reconnect attempts in milliseconds (default: 1000)
+    pub reconnect_delay_ms: u64,
+    /// Whether to resubscribe with `from_slot` set to the last processed slot so that
+    /// transactions from the disconnect gap are replayed (default: false).
+    /// Requires provider support; transactions of the resumed slot that were already delivered
+    /// are skipped. If the provider rejects the slot (e.g. outside its replay window), the next
+    /// attempt resubscribes without `from_slot` and the gap is logged.
+    pub resume_from_slot: bool,
+    /// HTTP2 keepalive ping interval in seconds, `None` disables keepalive pings (default: None)
+    pub keep_alive_interval: Option<u64>,
+    /// Seconds to wait for a keepalive ping ack before closing the connection (default: 20)
+    pub keep_alive_timeout: u64,
+    /// Whether to send keepalive pings while no stream is active (default: false)
+    pub keep_alive_while_idle: bool,
+    /// Whether to use HTTP2 adaptive flow control windows (default: false)
+    pub http2_adaptive_window: bool,
+    /// Interval in milliseconds for sending ping requests on the subscription stream,
+    /// `None` or `Some(0)` only answers server pings (default: None).
+    /// If no message (including pongs) arrives for 3 intervals the stream is ended so that
+    /// `auto_reconnect` can take over.
+    pub ping_interval_ms: Option<u64>,
 }
 
 impl Default for ConnectionConfig {
@@ -47,6 +70,14 @@ impl Default for ConnectionConfig {
             connect_timeout: DEFAULT_CONNECT_TIMEOUT,
             request_timeout: DEFAULT_REQUEST_TIMEOUT,
             max_decoding_message_size: DEFAULT_MAX_DECODING_MESSAGE_SIZE,
+            auto_reconnect: false,
+            reconnect_delay_ms: DEFAULT_RECONNECT_DELAY_MS,
+            resume_from_slot: false,
+            keep_alive_interval: None,
+            keep_alive_timeout: DEFAULT_KEEP_ALIVE_TIMEOUT,
+            keep_alive_while_idle: false,
+            http2_adaptive_window: false,
+            ping_interval_ms: None,
         }
     }
 }
@@ -60,6 +91,13 @@ pub struct StreamClientConfig {
     pub backpressure: BackpressureConfig,
     /// Whether performance monitoring is enabled (default: false)
     pub enable_metrics: bool,
+    /// Whether to also subscribe to failed transactions (default: false).
+    /// Events parsed from a failed transaction are flagged via `UnifiedEvent::tx_failed()`.
+    /// Only applies to Yellowstone gRPC subscriptions.
+    pub include_failed_transactions: bool,
+    /// Whether to skip transactions signed by the `bot_wallet` given to subscribe (default: false).
+    /// Prevents reacting to the bot's own trades when its wallet is also being monitored.
+    pub skip_bot_wallet_transactions: bool,
 }
 
 impl Default for StreamClientConfig {
@@ -68,6 +106,8 @@ impl Default for StreamClientConfig {
             connection: ConnectionConfig::default(),
             backpressure: BackpressureConfig::default(),
             enable_metrics: false,
+            include_failed_transactions: false,
+            skip_bot_wallet_transactions: false,
         }
     }
 }
@@ -89,6 +129,8 @@ impl StreamClientConfig {
                 strategy: BackpressureStrategy::Drop,
             },
             enable_metrics: false,
+            include_failed_transactions: false,
+            skip_bot_wallet_transactions: false,
         }
     }
 
@@ -106,6 +148,8 @@ impl StreamClientConfig {
             connection: ConnectionConfig::default(),
             backpressure: BackpressureConfig { permits: 4000, strategy: BackpressureStrategy::Block },
             enable_metrics: false,
+            include_failed_transactions: false,
+            skip_bot_wallet_transactions: false,
         }
     }
 
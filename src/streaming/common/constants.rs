@@ -5,6 +5,10 @@ pub const DEFAULT_CONNECT_TIMEOUT: u64 = 10;
 pub const DEFAULT_REQUEST_TIMEOUT: u64 = 60;
 pub const DEFAULT_CHANNEL_SIZE: usize = 1000;
 pub const DEFAULT_MAX_DECODING_MESSAGE_SIZE: usize = 1024 * 1024 * 10;
+pub const DEFAULT_RECONNECT_DELAY_MS: u64 = 1000;
+pub const DEFAULT_KEEP_ALIVE_TIMEOUT: u64 = 20;
+// 连续多少个 ping 周期未收到任何消息后判定连接失效
+pub const MAX_MISSED_PINGS: u32 = 3;
 
 // 性能监控相关常量
 pub const DEFAULT_METRICS_WINDOW_SECONDS: u64 = 5;
@@ -178,6 +178,23 @@ impl EventProcessor {
             }
             EventPretty::Transaction(transaction_pretty) => {
                 self.metrics_manager.add_tx_process_count();
+                let signers = transaction_pretty
+                    .grpc_tx
+                    .transaction
+                    .as_ref()
+                    .and_then(|tx| tx.message.as_ref())
+                    .map(|message| {
+                        let num_signers = message
+                            .header
+                            .as_ref()
+                            .map_or(0, |header| header.num_required_signatures as usize);
+                        message.account_keys.iter().take(num_signers).map(Vec::as_slice)
+                    })
+                    .into_iter()
+                    .flatten();
+                if self.is_signed_by_bot_wallet(signers, bot_wallet) {
+                    return Ok(());
+                }
                 let slot = transaction_pretty.slot;
                 let signature = transaction_pretty.signature;
                 let block_time = transaction_pretty.block_time;
@@ -299,6 +316,12 @@ impl EventProcessor {
         }
         self.metrics_manager.add_tx_process_count();
         let tx = transaction_with_slot.transaction;
+        let num_signers = tx.message.header().num_required_signatures as usize;
+        let signers =
+            tx.message.static_account_keys().iter().take(num_signers).map(|key| key.as_ref());
+        if self.is_signed_by_bot_wallet(signers, bot_wallet) {
+            return Ok(());
+        }
 
         let slot = transaction_with_slot.slot;
         let signature = tx.signatures[0];
@@ -323,6 +346,20 @@ impl EventProcessor {
         Ok(())
     }
 
+    /// 开启 skip_bot_wallet_transactions 时，判断交易是否由 bot 钱包签名
+    fn is_signed_by_bot_wallet<'a>(
+        &self,
+        mut signers: impl Iterator<Item = &'a [u8]>,
+        bot_wallet: Option<Pubkey>,
+    ) -> bool {
+        match bot_wallet {
+            Some(bot_wallet) if self.config.skip_bot_wallet_transactions => {
+                signers.any(|key| key == bot_wallet.as_ref())
+            }
+            _ => false,
+        }
+    }
+
     fn update_metrics(&self, ty: MetricsEventType, count: u64, time_us: f64) {
         self.metrics_manager.update_metrics(ty, count, time_us);
     }
@@ -354,7 +391,7 @@ impl EventProcessor {
                     if let Err(e) = rt.block_on(
                         processor.process_grpc_event_transaction(event_pretty, bot_wallet),
                     ) {
-                        println!("Error processing gRPC event: {}", e);
+                        log::error!("Error processing gRPC event: {}", e);
                     }
                 } else {
                     // Yield to reduce CPU usage in busy wait
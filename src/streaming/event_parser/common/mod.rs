@@ -1,6 +1,8 @@
 pub mod types;
 pub mod utils;
 pub mod filter;
+#[cfg(test)]
+pub(crate) mod test_utils;
 
 /// 自动生成UnifiedEvent trait实现的宏
 #[macro_export]
@@ -70,6 +72,14 @@ macro_rules! impl_unified_event {
             fn transaction_index(&self) -> Option<u64> {
                 self.metadata.transaction_index
             }
+
+            fn tx_failed(&self) -> bool {
+                self.metadata.tx_failed
+            }
+
+            fn set_tx_failed(&mut self, tx_failed: bool) {
+                self.metadata.tx_failed = tx_failed;
+            }
         }
     };
 }
@@ -0,0 +1,6 @@
+use solana_sdk::pubkey::Pubkey;
+
+/// 生成 `len` 个互不相同的账户地址，用于构造指令账户列表
+pub(crate) fn unique_accounts(len: usize) -> Vec<Pubkey> {
+    (0..len).map(|_| Pubkey::new_unique()).collect()
+}
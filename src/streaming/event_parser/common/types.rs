@@ -22,6 +22,9 @@ use crate::{
     },
 };
 
+/// Wrapped SOL mint
+pub use spl_token::native_mint::ID as WSOL_MINT;
+
 // Object pool size configuration
 const EVENT_METADATA_POOL_SIZE: usize = 1000;
 const TRANSFER_DATA_POOL_SIZE: usize = 2000;
@@ -308,6 +311,8 @@ pub struct EventMetadata {
     pub swap_data: Option<SwapData>,
     pub outer_index: i64,
     pub inner_index: Option<i64>,
+    #[serde(default)]
+    pub tx_failed: bool, // 交易是否执行失败
 }
 
 impl EventMetadata {
@@ -339,6 +344,7 @@ impl EventMetadata {
             outer_index,
             inner_index,
             transaction_index,
+            tx_failed: false,
         }
     }
 
@@ -353,7 +359,6 @@ impl EventMetadata {
 }
 
 lazy_static::lazy_static! {
-    static ref SOL_MINT: Pubkey = Pubkey::from_str("So11111111111111111111111111111111111111111").unwrap();
     static ref SYSTEM_PROGRAMS: [Pubkey; 3] = [
         Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap(),
         Pubkey::from_str("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb").unwrap(),
@@ -396,8 +401,8 @@ pub fn parse_swap_data_from_next_instructions(
             to_vault = Some(e.quote_vault);
         },
         PumpFunTradeEvent => |e: PumpFunTradeEvent| {
-            swap_data.from_mint = if e.is_buy { *SOL_MINT } else { e.mint };
-            swap_data.to_mint   = if e.is_buy { e.mint } else { *SOL_MINT };
+            swap_data.from_mint = if e.is_buy { WSOL_MINT } else { e.mint };
+            swap_data.to_mint   = if e.is_buy { e.mint } else { WSOL_MINT };
         },
         PumpSwapBuyEvent => |e: PumpSwapBuyEvent| {
             swap_data.from_mint = e.quote_mint;
@@ -563,8 +568,8 @@ pub fn parse_swap_data_from_next_grpc_instructions(
             to_vault = Some(e.quote_vault);
         },
         PumpFunTradeEvent => |e: PumpFunTradeEvent| {
-            swap_data.from_mint = if e.is_buy { *SOL_MINT } else { e.mint };
-            swap_data.to_mint   = if e.is_buy { e.mint } else { *SOL_MINT };
+            swap_data.from_mint = if e.is_buy { WSOL_MINT } else { e.mint };
+            swap_data.to_mint   = if e.is_buy { e.mint } else { WSOL_MINT };
         },
         PumpSwapBuyEvent => |e: PumpSwapBuyEvent| {
             swap_data.from_mint = e.quote_mint;
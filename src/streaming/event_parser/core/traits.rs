@@ -270,6 +270,31 @@ pub trait UnifiedEvent: Debug + Send + Sync {
 
     /// Get transaction index in slot
     fn transaction_index(&self) -> Option<u64>;
+
+    /// Whether the transaction carrying this event failed on-chain
+    fn tx_failed(&self) -> bool {
+        false
+    }
+
+    /// Mark the event as coming from a failed transaction
+    fn set_tx_failed(&mut self, _tx_failed: bool) {
+        // Default implementation: events without metadata cannot be flagged
+    }
+}
+
+/// 失败交易的事件需要标记 tx_failed 后再回调
+fn tx_failed_callback(
+    callback: Arc<dyn for<'a> Fn(&'a Box<dyn UnifiedEvent>) + Send + Sync>,
+    tx_failed: bool,
+) -> Arc<dyn for<'a> Fn(&'a Box<dyn UnifiedEvent>) + Send + Sync> {
+    if !tx_failed {
+        return callback;
+    }
+    Arc::new(move |event: &Box<dyn UnifiedEvent>| {
+        let mut event = event.clone_boxed();
+        event.set_tx_failed(true);
+        callback(&event);
+    })
 }
 
 /// 事件解析器trait - 定义了事件解析的核心方法
@@ -562,8 +587,10 @@ pub trait EventParser: Send + Sync {
                 let mut inner_instructions: Vec<
                     yellowstone_grpc_proto::solana::storage::confirmed_block::InnerInstructions,
                 > = vec![];
+                let mut tx_failed = false;
 
                 if let Some(meta) = grpc_tx.meta {
+                    tx_failed = meta.err.is_some();
                     inner_instructions = meta.inner_instructions;
                     address_table_lookups.reserve(
                         meta.loaded_writable_addresses.len() + meta.loaded_writable_addresses.len(),
@@ -593,6 +620,7 @@ pub trait EventParser: Send + Sync {
                 // 使用 Arc 包装共享数据，避免不必要的克隆
                 let accounts_arc = Arc::new(accounts);
                 let inner_instructions_arc = Arc::new(inner_instructions);
+                let callback = tx_failed_callback(callback, tx_failed);
                 // 解析指令事件
                 let instructions = &message.instructions;
                 self.parse_instruction_events_from_grpc_transaction(
@@ -700,7 +728,9 @@ pub trait EventParser: Send + Sync {
 
         let meta = transaction.transaction.meta;
         let mut address_table_lookups: Vec<Pubkey> = vec![];
+        let mut tx_failed = false;
         if let Some(meta) = meta {
+            tx_failed = meta.err.is_some();
             if let solana_transaction_status::option_serializer::OptionSerializer::Some(
                 loaded_addresses,
             ) = &meta.loaded_addresses
@@ -735,6 +765,7 @@ pub trait EventParser: Send + Sync {
         let recv_us = get_high_perf_clock();
         let bot_wallet = None;
         let transaction_index = None;
+        let callback = tx_failed_callback(callback, tx_failed);
         // 解析指令事件
         self.parse_instruction_events_from_versioned_transaction(
             &versioned_tx,
@@ -307,7 +307,7 @@ impl BonkEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 24 {
+        if data.len() < 24 || accounts.len() < 10 {
             return None;
         }
 
@@ -340,7 +340,7 @@ impl BonkEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 24 {
+        if data.len() < 24 || accounts.len() < 10 {
             return None;
         }
 
@@ -348,7 +348,7 @@ impl BonkEventParser {
         let base_mint_param = Self::parse_mint_params(data, &mut offset)?;
         let curve_param = Self::parse_curve_params(data, &mut offset)?;
         let vesting_param = Self::parse_vesting_params(data, &mut offset)?;
-        let amm_fee_on = data[offset];
+        let amm_fee_on = read_u8(data, offset)?;
 
         Some(Box::new(BonkPoolCreateEvent {
             metadata,
@@ -484,13 +484,13 @@ impl BonkEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 16 {
+        if data.len() < 17 || accounts.len() < 32 {
             return None;
         }
 
-        let base_lot_size = u64::from_le_bytes(data[0..8].try_into().unwrap());
-        let quote_lot_size = u64::from_le_bytes(data[8..16].try_into().unwrap());
-        let market_vault_signer_nonce = data[16];
+        let base_lot_size = read_u64_le(data, 0)?;
+        let quote_lot_size = read_u64_le(data, 8)?;
+        let market_vault_signer_nonce = read_u8(data, 16)?;
 
         Some(Box::new(BonkMigrateToAmmEvent {
             metadata,
@@ -539,6 +539,10 @@ impl BonkEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 28 {
+            return None;
+        }
+
         Some(Box::new(BonkMigrateToCpswapEvent {
             metadata,
             payer: accounts[0],
@@ -576,3 +580,57 @@ impl BonkEventParser {
 }
 
 impl_event_parser_delegate!(BonkEventParser);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::common::test_utils::unique_accounts;
+
+    #[test]
+    fn migrate_to_amm_without_nonce_byte_returns_none() {
+        assert!(BonkEventParser::parse_migrate_to_amm_instruction(
+            &[0u8; 16],
+            &unique_accounts(32),
+            EventMetadata::default()
+        )
+        .is_none());
+        assert!(BonkEventParser::parse_migrate_to_amm_instruction(
+            &[0u8; 17],
+            &unique_accounts(32),
+            EventMetadata::default()
+        )
+        .is_some());
+    }
+
+    #[test]
+    fn migrate_with_missing_accounts_returns_none() {
+        assert!(BonkEventParser::parse_migrate_to_amm_instruction(
+            &[0u8; 17],
+            &unique_accounts(31),
+            EventMetadata::default()
+        )
+        .is_none());
+        assert!(BonkEventParser::parse_migrate_to_cpswap_instruction(
+            &[],
+            &unique_accounts(27),
+            EventMetadata::default()
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn initialize_with_missing_accounts_returns_none() {
+        assert!(BonkEventParser::parse_initialize_instruction(
+            &[0u8; 64],
+            &unique_accounts(9),
+            EventMetadata::default()
+        )
+        .is_none());
+        assert!(BonkEventParser::parse_initialize_v2_instruction(
+            &[0u8; 64],
+            &unique_accounts(9),
+            EventMetadata::default()
+        )
+        .is_none());
+    }
+}
@@ -3,7 +3,7 @@ use solana_sdk::pubkey::Pubkey;
 use crate::{
     impl_event_parser_delegate,
     streaming::event_parser::{
-        common::{EventMetadata, EventType, ProtocolType},
+        common::{read_u32_le, read_u64_le, EventMetadata, EventType, ProtocolType},
         core::traits::{GenericEventParseConfig, GenericEventParser, UnifiedEvent},
         protocols::pumpfun::{
             discriminators, pumpfun_create_token_event_log_decode,
@@ -121,17 +121,17 @@ impl PumpFunEventParser {
             return None;
         }
         let mut offset = 0;
-        let name_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        let name_len = read_u32_le(data, offset)? as usize;
         offset += 4;
-        let name = String::from_utf8_lossy(&data[offset..offset + name_len]);
+        let name = String::from_utf8_lossy(data.get(offset..offset + name_len)?);
         offset += name_len;
-        let symbol_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        let symbol_len = read_u32_le(data, offset)? as usize;
         offset += 4;
-        let symbol = String::from_utf8_lossy(&data[offset..offset + symbol_len]);
+        let symbol = String::from_utf8_lossy(data.get(offset..offset + symbol_len)?);
         offset += symbol_len;
-        let uri_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        let uri_len = read_u32_le(data, offset)? as usize;
         offset += 4;
-        let uri = String::from_utf8_lossy(&data[offset..offset + uri_len]);
+        let uri = String::from_utf8_lossy(data.get(offset..offset + uri_len)?);
         offset += uri_len;
         let creator = if offset + 32 <= data.len() {
             Pubkey::new_from_array(data[offset..offset + 32].try_into().ok()?)
@@ -160,11 +160,11 @@ impl PumpFunEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 16 || accounts.len() < 13 {
+        if data.len() < 16 || accounts.len() < 12 {
             return None;
         }
-        let amount = u64::from_le_bytes(data[0..8].try_into().unwrap());
-        let max_sol_cost = u64::from_le_bytes(data[8..16].try_into().unwrap());
+        let amount = read_u64_le(data, 0)?;
+        let max_sol_cost = read_u64_le(data, 8)?;
         Some(Box::new(PumpFunTradeEvent {
             metadata,
             global: accounts[0],
@@ -179,8 +179,8 @@ impl PumpFunEventParser {
             creator_vault: accounts[9],
             event_authority: accounts[10],
             program: accounts[11],
-            global_volume_accumulator: accounts[12],
-            user_volume_accumulator: accounts[13],
+            global_volume_accumulator: *accounts.get(12).unwrap_or(&Pubkey::default()),
+            user_volume_accumulator: *accounts.get(13).unwrap_or(&Pubkey::default()),
             max_sol_cost,
             amount,
             is_buy: true,
@@ -194,11 +194,11 @@ impl PumpFunEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 16 || accounts.len() < 11 {
+        if data.len() < 16 || accounts.len() < 12 {
             return None;
         }
-        let amount = u64::from_le_bytes(data[0..8].try_into().unwrap());
-        let min_sol_output = u64::from_le_bytes(data[8..16].try_into().unwrap());
+        let amount = read_u64_le(data, 0)?;
+        let min_sol_output = read_u64_le(data, 8)?;
         Some(Box::new(PumpFunTradeEvent {
             metadata,
             global: accounts[0],
@@ -263,3 +263,117 @@ impl PumpFunEventParser {
 }
 
 impl_event_parser_delegate!(PumpFunEventParser);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::common::test_utils::unique_accounts;
+
+    fn borsh_string(data: &mut Vec<u8>, len: u32, content: &[u8]) {
+        data.extend_from_slice(&len.to_le_bytes());
+        data.extend_from_slice(content);
+    }
+
+    #[test]
+    fn create_token_with_oversized_string_len_returns_none() {
+        let accounts = unique_accounts(11);
+        let mut name_oversized = Vec::new();
+        borsh_string(&mut name_oversized, u32::MAX, b"name");
+        name_oversized.resize(32, 0);
+
+        let mut symbol_oversized = Vec::new();
+        borsh_string(&mut symbol_oversized, 4, b"name");
+        borsh_string(&mut symbol_oversized, 1_000, b"sym");
+        symbol_oversized.resize(32, 0);
+
+        let mut uri_oversized = Vec::new();
+        borsh_string(&mut uri_oversized, 4, b"name");
+        borsh_string(&mut uri_oversized, 3, b"sym");
+        borsh_string(&mut uri_oversized, 1_000, b"uri");
+
+        for data in [name_oversized, symbol_oversized, uri_oversized] {
+            assert!(PumpFunEventParser::parse_create_token_instruction(
+                &data,
+                &accounts,
+                EventMetadata::default()
+            )
+            .is_none());
+        }
+    }
+
+    #[test]
+    fn create_token_truncated_before_string_len_returns_none() {
+        let mut data = Vec::new();
+        borsh_string(&mut data, 12, b"abcdefghijkl");
+        assert!(PumpFunEventParser::parse_create_token_instruction(
+            &data,
+            &unique_accounts(11),
+            EventMetadata::default()
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn buy_with_legacy_account_counts_does_not_panic() {
+        let data = [1u64.to_le_bytes(), 2u64.to_le_bytes()].concat();
+        assert!(PumpFunEventParser::parse_buy_instruction(
+            &data,
+            &unique_accounts(11),
+            EventMetadata::default()
+        )
+        .is_none());
+
+        let accounts = unique_accounts(12);
+        let event =
+            PumpFunEventParser::parse_buy_instruction(&data, &accounts, EventMetadata::default())
+                .expect("buy with the 12 fixed accounts should parse");
+        let event = event.as_any().downcast_ref::<PumpFunTradeEvent>().unwrap();
+        assert_eq!(event.program, accounts[11]);
+        assert_eq!(event.global_volume_accumulator, Pubkey::default());
+        assert_eq!(event.user_volume_accumulator, Pubkey::default());
+
+        let accounts = unique_accounts(13);
+        let event =
+            PumpFunEventParser::parse_buy_instruction(&data, &accounts, EventMetadata::default())
+                .expect("buy with 13 accounts should parse");
+        let event = event.as_any().downcast_ref::<PumpFunTradeEvent>().unwrap();
+        assert_eq!(event.program, accounts[11]);
+        assert_eq!(event.global_volume_accumulator, accounts[12]);
+        assert_eq!(event.user_volume_accumulator, Pubkey::default());
+    }
+
+    #[test]
+    fn sell_with_11_accounts_returns_none() {
+        let data = [1u64.to_le_bytes(), 2u64.to_le_bytes()].concat();
+        assert!(PumpFunEventParser::parse_sell_instruction(
+            &data,
+            &unique_accounts(11),
+            EventMetadata::default()
+        )
+        .is_none());
+        assert!(PumpFunEventParser::parse_sell_instruction(
+            &data,
+            &unique_accounts(12),
+            EventMetadata::default()
+        )
+        .is_some());
+    }
+
+    #[test]
+    fn trade_with_truncated_data_returns_none() {
+        let data = [0u8; 15];
+        let accounts = unique_accounts(14);
+        assert!(PumpFunEventParser::parse_buy_instruction(
+            &data,
+            &accounts,
+            EventMetadata::default()
+        )
+        .is_none());
+        assert!(PumpFunEventParser::parse_sell_instruction(
+            &data,
+            &accounts,
+            EventMetadata::default()
+        )
+        .is_none());
+    }
+}
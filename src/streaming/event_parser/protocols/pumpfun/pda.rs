@@ -0,0 +1,77 @@
+use solana_sdk::pubkey::Pubkey;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+use crate::streaming::event_parser::protocols::pumpfun::parser::PUMPFUN_PROGRAM_ID;
+
+/// 全局配置账户 PDA 种子
+pub const GLOBAL_SEED: &[u8] = b"global";
+/// 铸币曲线账户 PDA 种子
+pub const BONDING_CURVE_SEED: &[u8] = b"bonding-curve";
+/// 创建者手续费金库 PDA 种子
+pub const CREATOR_VAULT_SEED: &[u8] = b"creator-vault";
+/// 事件权限账户 PDA 种子
+pub const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
+/// 全局交易量累加器 PDA 种子
+pub const GLOBAL_VOLUME_ACCUMULATOR_SEED: &[u8] = b"global_volume_accumulator";
+/// 用户交易量累加器 PDA 种子
+pub const USER_VOLUME_ACCUMULATOR_SEED: &[u8] = b"user_volume_accumulator";
+
+/// 全局配置账户地址
+pub fn get_global_pda() -> Pubkey {
+    Pubkey::find_program_address(&[GLOBAL_SEED], &PUMPFUN_PROGRAM_ID).0
+}
+
+/// 铸币曲线账户地址
+pub fn get_bonding_curve_pda(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[BONDING_CURVE_SEED, mint.as_ref()], &PUMPFUN_PROGRAM_ID).0
+}
+
+/// 铸币曲线持有代币的关联账户地址
+pub fn get_associated_bonding_curve_pda(mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(&get_bonding_curve_pda(mint), mint, token_program)
+}
+
+/// 创建者手续费金库地址
+pub fn get_creator_vault_pda(creator: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[CREATOR_VAULT_SEED, creator.as_ref()], &PUMPFUN_PROGRAM_ID).0
+}
+
+/// 事件权限账户地址
+pub fn get_event_authority_pda() -> Pubkey {
+    Pubkey::find_program_address(&[EVENT_AUTHORITY_SEED], &PUMPFUN_PROGRAM_ID).0
+}
+
+/// 全局交易量累加器地址
+pub fn get_global_volume_accumulator_pda() -> Pubkey {
+    Pubkey::find_program_address(&[GLOBAL_VOLUME_ACCUMULATOR_SEED], &PUMPFUN_PROGRAM_ID).0
+}
+
+/// 用户交易量累加器地址
+pub fn get_user_volume_accumulator_pda(user: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[USER_VOLUME_ACCUMULATOR_SEED, user.as_ref()],
+        &PUMPFUN_PROGRAM_ID,
+    )
+    .0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn program_pdas_match_mainnet() {
+        assert_eq!(
+            get_global_pda(),
+            solana_sdk::pubkey!("4wTV1YmiEkRvAtNtsSGPtUrqRYQMe5SKy2uB4Jjaxnjf")
+        );
+        assert_eq!(
+            get_event_authority_pda(),
+            solana_sdk::pubkey!("Ce6TQqeHC9p8KetsN6JsjHK7UTZk7nasjjnr7XxXp9F1")
+        );
+        assert_eq!(
+            get_global_volume_accumulator_pda(),
+            solana_sdk::pubkey!("Hq2wp8uJ9jCPsYgNHex8RtqdvMPfVGoYwjvF1ATiwn2Y")
+        );
+    }
+}
@@ -151,7 +151,7 @@ impl PumpSwapEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 16 || accounts.len() < 11 {
+        if data.len() < 16 || accounts.len() < 13 {
             return None;
         }
 
@@ -186,7 +186,7 @@ impl PumpSwapEventParser {
         accounts: &[Pubkey],
         metadata: EventMetadata,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if data.len() < 16 || accounts.len() < 11 {
+        if data.len() < 16 || accounts.len() < 13 {
             return None;
         }
 
@@ -320,3 +320,47 @@ impl PumpSwapEventParser {
 }
 
 impl_event_parser_delegate!(PumpSwapEventParser);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::common::test_utils::unique_accounts;
+
+    #[test]
+    fn trade_with_missing_token_program_accounts_returns_none() {
+        let data = [1u64.to_le_bytes(), 2u64.to_le_bytes()].concat();
+        for len in [11, 12] {
+            let accounts = unique_accounts(len);
+            assert!(PumpSwapEventParser::parse_buy_instruction(
+                &data,
+                &accounts,
+                EventMetadata::default()
+            )
+            .is_none());
+            assert!(PumpSwapEventParser::parse_sell_instruction(
+                &data,
+                &accounts,
+                EventMetadata::default()
+            )
+            .is_none());
+        }
+    }
+
+    #[test]
+    fn trade_with_all_accounts_parses() {
+        let data = [1u64.to_le_bytes(), 2u64.to_le_bytes()].concat();
+        let accounts = unique_accounts(13);
+        assert!(PumpSwapEventParser::parse_buy_instruction(
+            &data,
+            &accounts,
+            EventMetadata::default()
+        )
+        .is_some());
+        assert!(PumpSwapEventParser::parse_sell_instruction(
+            &data,
+            &accounts,
+            EventMetadata::default()
+        )
+        .is_some());
+    }
+}
@@ -1,5 +1,6 @@
 pub mod events;
 pub mod parser;
+pub mod pda;
 pub mod types;
 
 pub use events::*;
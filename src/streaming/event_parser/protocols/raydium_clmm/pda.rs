@@ -0,0 +1,206 @@
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::protocols::raydium_clmm::{
+    parser::RAYDIUM_CLMM_PROGRAM_ID, types::PoolState,
+};
+
+/// 每个 tick array 包含的 tick 数量
+pub const TICK_ARRAY_SIZE: i32 = 60;
+/// tick array PDA 种子
+pub const TICK_ARRAY_SEED: &[u8] = b"tick_array";
+/// 池子 tick_array_bitmap 单侧覆盖的 tick array 数量（共 1024 位）
+pub const TICK_ARRAY_BITMAP_SIZE: i32 = 512;
+/// 最小 tick
+pub const MIN_TICK: i32 = -443636;
+/// 最大 tick
+pub const MAX_TICK: i32 = 443636;
+
+/// 计算 tick 所在 tick array 的起始 tick index，`tick_spacing` 为 0 时返回 None
+pub fn get_tick_array_start_index(tick_index: i32, tick_spacing: u16) -> Option<i32> {
+    if tick_spacing == 0 {
+        return None;
+    }
+    let ticks_in_array = TICK_ARRAY_SIZE * tick_spacing as i32;
+    Some(tick_index.div_euclid(ticks_in_array) * ticks_in_array)
+}
+
+/// 根据池子和起始 tick index 推导 tick array 地址
+pub fn get_tick_array_pda(pool_state: &Pubkey, start_tick_index: i32) -> Pubkey {
+    Pubkey::find_program_address(
+        &[TICK_ARRAY_SEED, pool_state.as_ref(), &start_tick_index.to_be_bytes()],
+        &RAYDIUM_CLMM_PROGRAM_ID,
+    )
+    .0
+}
+
+/// 查询池子 tick_array_bitmap 中 tick array 是否已初始化
+///
+/// 超出池子 bitmap 覆盖范围（需要 TickArrayBitmapExtension 账户）时返回 None。
+pub fn is_tick_array_initialized(
+    tick_array_bitmap: &[u64; 16],
+    start_tick_index: i32,
+    tick_spacing: u16,
+) -> Option<bool> {
+    if tick_spacing == 0 {
+        return None;
+    }
+    let ticks_in_array = TICK_ARRAY_SIZE * tick_spacing as i32;
+    let offset = start_tick_index.div_euclid(ticks_in_array) + TICK_ARRAY_BITMAP_SIZE;
+    if !(0..TICK_ARRAY_BITMAP_SIZE * 2).contains(&offset) {
+        return None;
+    }
+    let offset = offset as usize;
+    Some((tick_array_bitmap[offset / 64] >> (offset % 64)) & 1 == 1)
+}
+
+/// 按兑换方向推导 swap 指令需要的已初始化 tick array 地址列表
+///
+/// 从当前 tick 所在的 tick array 开始，`zero_for_one` 为 true 时向更小的 tick 查找，
+/// 否则向更大的 tick 查找，依次返回池子 bitmap 中已初始化的 tick array，最多 `count` 个。
+/// 只查询池子自身的 bitmap，超出其覆盖范围的 tick array 不会返回。
+pub fn derive_tick_arrays(
+    pool_id: &Pubkey,
+    pool_state: &PoolState,
+    zero_for_one: bool,
+    count: usize,
+) -> Vec<Pubkey> {
+    let tick_spacing = pool_state.tick_spacing;
+    let (Some(mut start_index), Some(min_start_index), Some(max_start_index)) = (
+        get_tick_array_start_index(pool_state.tick_current, tick_spacing),
+        get_tick_array_start_index(MIN_TICK, tick_spacing),
+        get_tick_array_start_index(MAX_TICK, tick_spacing),
+    ) else {
+        return Vec::new();
+    };
+    let ticks_in_array = TICK_ARRAY_SIZE * tick_spacing as i32;
+
+    let mut tick_arrays = Vec::with_capacity(count);
+    while tick_arrays.len() < count && (min_start_index..=max_start_index).contains(&start_index) {
+        match is_tick_array_initialized(&pool_state.tick_array_bitmap, start_index, tick_spacing) {
+            Some(true) => tick_arrays.push(get_tick_array_pda(pool_id, start_index)),
+            Some(false) => {}
+            None => break,
+        }
+        if zero_for_one {
+            start_index -= ticks_in_array;
+        } else {
+            start_index += ticks_in_array;
+        }
+    }
+    tick_arrays
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Raydium CLMM SOL/USDC 主网池子
+    const SOL_USDC_POOL: Pubkey =
+        solana_sdk::pubkey!("3ucNos4NbumPLZNWztqGHNFFgkHeRMBQAVemeeomsUxv");
+
+    fn pool_with_bitmap(tick_current: i32, tick_spacing: u16, start_indexes: &[i32]) -> PoolState {
+        let mut pool_state = PoolState { tick_current, tick_spacing, ..Default::default() };
+        let ticks_in_array = TICK_ARRAY_SIZE * tick_spacing as i32;
+        for start_index in start_indexes {
+            let offset = (start_index / ticks_in_array + TICK_ARRAY_BITMAP_SIZE) as usize;
+            pool_state.tick_array_bitmap[offset / 64] |= 1 << (offset % 64);
+        }
+        pool_state
+    }
+
+    #[test]
+    fn tick_array_pda_matches_mainnet_pool() {
+        assert_eq!(
+            get_tick_array_pda(&SOL_USDC_POOL, 0),
+            solana_sdk::pubkey!("8mmd9S2YL1JUhYpoPBi2FQe1ZUGgEYg7kNRYsYicPZx5")
+        );
+        assert_eq!(
+            get_tick_array_pda(&SOL_USDC_POOL, 5400),
+            solana_sdk::pubkey!("CfH1bxCVDyKvbdYCqR8xMJa2WJEQhwWuyRiBjUKyoRZp")
+        );
+        assert_eq!(
+            get_tick_array_pda(&SOL_USDC_POOL, -23040),
+            solana_sdk::pubkey!("HqXmFaFp46j6KtApxYBPLyQdbXPXMJCFU17C2V3BfVeo")
+        );
+    }
+
+    #[test]
+    fn start_index_rounds_toward_negative_infinity() {
+        assert_eq!(get_tick_array_start_index(0, 1), Some(0));
+        assert_eq!(get_tick_array_start_index(59, 1), Some(0));
+        assert_eq!(get_tick_array_start_index(60, 1), Some(60));
+        assert_eq!(get_tick_array_start_index(-1, 1), Some(-60));
+        assert_eq!(get_tick_array_start_index(-60, 1), Some(-60));
+        assert_eq!(get_tick_array_start_index(-61, 1), Some(-120));
+        assert_eq!(get_tick_array_start_index(-19_000, 64), Some(-19_200));
+        assert_eq!(get_tick_array_start_index(MIN_TICK, 1), Some(-443_640));
+        assert_eq!(get_tick_array_start_index(MAX_TICK, 1), Some(443_580));
+    }
+
+    #[test]
+    fn zero_tick_spacing_is_rejected() {
+        assert_eq!(get_tick_array_start_index(100, 0), None);
+        assert_eq!(is_tick_array_initialized(&[u64::MAX; 16], 0, 0), None);
+        assert!(
+            derive_tick_arrays(&SOL_USDC_POOL, &pool_with_bitmap(100, 0, &[]), true, 3).is_empty()
+        );
+    }
+
+    #[test]
+    fn derive_skips_uninitialized_arrays_in_both_directions() {
+        let pool_state = pool_with_bitmap(-30, 1, &[-300, -120, -60, 60, 240]);
+
+        assert_eq!(
+            derive_tick_arrays(&SOL_USDC_POOL, &pool_state, true, 3),
+            vec![
+                get_tick_array_pda(&SOL_USDC_POOL, -60),
+                get_tick_array_pda(&SOL_USDC_POOL, -120),
+                get_tick_array_pda(&SOL_USDC_POOL, -300),
+            ]
+        );
+        assert_eq!(
+            derive_tick_arrays(&SOL_USDC_POOL, &pool_state, false, 3),
+            vec![
+                get_tick_array_pda(&SOL_USDC_POOL, -60),
+                get_tick_array_pda(&SOL_USDC_POOL, 60),
+                get_tick_array_pda(&SOL_USDC_POOL, 240),
+            ]
+        );
+    }
+
+    #[test]
+    fn derive_stops_at_tick_bounds() {
+        // tick_spacing 足够大时 bitmap 覆盖整个 tick 范围，遍历由 MIN/MAX_TICK 终止
+        let tick_spacing = 60;
+        let ticks_in_array = TICK_ARRAY_SIZE * tick_spacing as i32;
+        let min_start = get_tick_array_start_index(MIN_TICK, tick_spacing).unwrap();
+        let max_start = get_tick_array_start_index(MAX_TICK, tick_spacing).unwrap();
+
+        let pool_state = pool_with_bitmap(MIN_TICK, tick_spacing, &[min_start]);
+        assert_eq!(
+            derive_tick_arrays(&SOL_USDC_POOL, &pool_state, true, 3),
+            vec![get_tick_array_pda(&SOL_USDC_POOL, min_start)]
+        );
+        let pool_state =
+            pool_with_bitmap(MAX_TICK, tick_spacing, &[max_start - ticks_in_array, max_start]);
+        assert_eq!(
+            derive_tick_arrays(&SOL_USDC_POOL, &pool_state, false, 3),
+            vec![get_tick_array_pda(&SOL_USDC_POOL, max_start)]
+        );
+    }
+
+    #[test]
+    fn derive_stops_outside_pool_bitmap() {
+        // tick_spacing 为 1 时 bitmap 只覆盖 [-30720, 30660]
+        assert_eq!(is_tick_array_initialized(&[u64::MAX; 16], -30_720, 1), Some(true));
+        assert_eq!(is_tick_array_initialized(&[u64::MAX; 16], 30_660, 1), Some(true));
+        assert_eq!(is_tick_array_initialized(&[u64::MAX; 16], -30_780, 1), None);
+        assert_eq!(is_tick_array_initialized(&[u64::MAX; 16], 30_720, 1), None);
+
+        let pool_state = pool_with_bitmap(30_700, 1, &[30_660]);
+        assert_eq!(
+            derive_tick_arrays(&SOL_USDC_POOL, &pool_state, false, 3),
+            vec![get_tick_array_pda(&SOL_USDC_POOL, 30_660)]
+        );
+    }
+}
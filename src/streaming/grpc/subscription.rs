@@ -32,12 +32,19 @@ impl SubscriptionManager {
 
     /// Create gRPC connection
     pub async fn connect(&self) -> AnyResult<GeyserGrpcClient<impl Interceptor>> {
-        let builder = GeyserGrpcClient::build_from_shared(self.endpoint.clone())?
+        let connection = &self.config.connection;
+        let mut builder = GeyserGrpcClient::build_from_shared(self.endpoint.clone())?
             .x_token(self.x_token.clone())?
             .tls_config(ClientTlsConfig::new().with_native_roots())?
-            .max_decoding_message_size(self.config.connection.max_decoding_message_size)
-            .connect_timeout(Duration::from_secs(self.config.connection.connect_timeout))
-            .timeout(Duration::from_secs(self.config.connection.request_timeout));
+            .max_decoding_message_size(connection.max_decoding_message_size)
+            .connect_timeout(Duration::from_secs(connection.connect_timeout))
+            .timeout(Duration::from_secs(connection.request_timeout))
+            .keep_alive_timeout(Duration::from_secs(connection.keep_alive_timeout))
+            .keep_alive_while_idle(connection.keep_alive_while_idle)
+            .http2_adaptive_window(connection.http2_adaptive_window);
+        if let Some(interval) = connection.keep_alive_interval {
+            builder = builder.http2_keep_alive_interval(Duration::from_secs(interval));
+        }
         Ok(builder.connect().await?)
     }
 
@@ -53,6 +60,20 @@ impl SubscriptionManager {
         impl Stream<Item = Result<SubscribeUpdate, Status>>,
         SubscribeRequest,
     )> {
+        let subscribe_request =
+            self.build_subscribe_request(transactions, accounts, commitment, event_type_filter);
+        let (sink, stream) = self.subscribe(subscribe_request.clone()).await?;
+        Ok((sink, stream, subscribe_request))
+    }
+
+    /// Build subscription request from filters
+    pub fn build_subscribe_request(
+        &self,
+        transactions: Option<TransactionsFilterMap>,
+        accounts: Option<AccountsFilterMap>,
+        commitment: Option<CommitmentLevel>,
+        event_type_filter: Option<&EventTypeFilter>,
+    ) -> SubscribeRequest {
         let blocks_meta =
             if event_type_filter.is_some() && event_type_filter.unwrap().include_block_event() {
                 hashmap! { "".to_owned() => SubscribeRequestFilterBlocksMeta {} }
@@ -61,7 +82,7 @@ impl SubscriptionManager {
             } else {
                 hashmap! {}
             };
-        let subscribe_request = SubscribeRequest {
+        SubscribeRequest {
             accounts: accounts.unwrap_or_default(),
             transactions: transactions.unwrap_or_default(),
             blocks_meta,
@@ -71,10 +92,20 @@ impl SubscriptionManager {
                 Some(CommitmentLevel::Processed.into())
             },
             ..Default::default()
-        };
+        }
+    }
+
+    /// Open a new connection and subscribe with an existing request
+    pub async fn subscribe(
+        &self,
+        subscribe_request: SubscribeRequest,
+    ) -> AnyResult<(
+        impl Sink<SubscribeRequest, Error = mpsc::SendError>,
+        impl Stream<Item = Result<SubscribeUpdate, Status>>,
+    )> {
         let mut client = self.connect().await?;
-        let (sink, stream) = client.subscribe_with_request(Some(subscribe_request.clone())).await?;
-        Ok((sink, stream, subscribe_request))
+        let (sink, stream) = client.subscribe_with_request(Some(subscribe_request)).await?;
+        Ok((sink, stream))
     }
 
     /// Create account subscription request and return stream
@@ -119,7 +150,11 @@ impl SubscriptionManager {
                 "client".to_string(),
                 SubscribeRequestFilterTransactions {
                     vote: Some(false),
-                    failed: Some(false),
+                    failed: if self.config.include_failed_transactions {
+                        None
+                    } else {
+                        Some(false)
+                    },
                     signature: None,
                     account_include: tf.account_include,
                     account_exclude: tf.account_exclude,
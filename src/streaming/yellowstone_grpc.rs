@@ -1,6 +1,7 @@
 use crate::common::AnyResult;
 use crate::streaming::common::{
     EventProcessor, MetricsManager, PerformanceMetrics, StreamClientConfig, SubscriptionHandle,
+    MAX_MISSED_PINGS,
 };
 use crate::streaming::event_parser::common::filter::EventTypeFilter;
 use crate::streaming::event_parser::{Protocol, UnifiedEvent};
@@ -12,8 +13,11 @@ use futures::channel::mpsc;
 use futures::{SinkExt, StreamExt};
 use log::error;
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use std::collections::HashSet;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, RwLock};
+use std::time::Duration;
 use tokio::sync::Mutex;
 use yellowstone_grpc_proto::geyser::subscribe_update::UpdateOneof;
 use yellowstone_grpc_proto::geyser::{
@@ -196,10 +200,14 @@ impl YellowstoneGrpc {
             .subscribe_with_account_request(account_filter, event_type_filter.as_ref());
 
         // 订阅事件
-        let (mut subscribe_tx, mut stream, subscribe_request) = self
-            .subscription_manager
-            .subscribe_with_request(transactions, accounts, commitment, event_type_filter.as_ref())
-            .await?;
+        let subscribe_request = self.subscription_manager.build_subscribe_request(
+            transactions,
+            accounts,
+            commitment,
+            event_type_filter.as_ref(),
+        );
+        let (subscribe_tx, mut stream) =
+            self.subscription_manager.subscribe(subscribe_request.clone()).await?;
 
         // 用 Arc<Mutex<>> 包装 subscribe_tx 以支持多线程共享
         let subscribe_tx = Arc::new(Mutex::new(subscribe_tx));
@@ -215,88 +223,215 @@ impl YellowstoneGrpc {
             self.config.backpressure.clone(),
             Some(Arc::new(callback)),
         );
+        let subscription_manager = self.subscription_manager.clone();
+        let current_request = self.current_request.clone();
+        let connection_config = self.config.connection.clone();
         let stream_handle = tokio::spawn(async move {
-            loop {
-                tokio::select! {
-                    message = stream.next() => {
-                        match message {
-                            Some(Ok(msg)) => {
-                                let created_at = msg.created_at;
-                                match msg.update_oneof {
-                                    Some(UpdateOneof::Account(account)) => {
-                                        let account_pretty = factory::create_account_pretty_pooled(account);
-                                        log::debug!("Received account: {:?}", account_pretty);
-                                        if let Err(e) = event_processor
-                                            .process_grpc_event_transaction_with_metrics(
-                                                EventPretty::Account(account_pretty),
-                                                bot_wallet,
-                                            )
-                                            .await
-                                        {
-                                            error!("Error processing account event: {e:?}");
+            // 最近处理的 slot，用于断线重连时通过 from_slot 补齐数据
+            let mut last_slot: Option<u64> = None;
+            // 当前流是否以 from_slot 续订
+            let mut resumed = false;
+            // 流因错误结束时的状态码，用于判断 from_slot 是否被服务端拒绝
+            let mut last_error_code: Option<tonic::Code> = None;
+            // from_slot 被服务端拒绝（超出回放窗口）后，下一次重连不再携带 from_slot
+            let mut resume_rejected = false;
+            // 最新交易 slot 中已处理的交易签名，续订后用于跳过该 slot 内重复推送的交易
+            let mut delivered_slot: Option<u64> = None;
+            let mut delivered_signatures: HashSet<Signature> = HashSet::new();
+            // 续订的起始 slot，流越过该 slot 之前需要去重
+            let mut replay_until_slot: Option<u64> = None;
+            // 主动发送 ping，尽早发现已失效的连接
+            let ping_period =
+                connection_config.ping_interval_ms.filter(|ms| *ms > 0).map(Duration::from_millis);
+            let mut ping_interval = ping_period.map(|period| {
+                let mut interval =
+                    tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+                interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+                interval
+            });
+            let mut ping_id: i32 = 0;
+            // 最近一次收到服务端消息（包括 pong）的时间，超时未收到则视为连接已失效
+            let mut last_message_at = tokio::time::Instant::now();
+            'subscription: loop {
+                loop {
+                    tokio::select! {
+                        message = stream.next() => {
+                            match message {
+                                Some(Ok(msg)) => {
+                                    last_message_at = tokio::time::Instant::now();
+                                    let created_at = msg.created_at;
+                                    match msg.update_oneof {
+                                        Some(UpdateOneof::Account(account)) => {
+                                            last_slot = last_slot.max(Some(account.slot));
+                                            let account_pretty = factory::create_account_pretty_pooled(account);
+                                            log::debug!("Received account: {:?}", account_pretty);
+                                            if let Err(e) = event_processor
+                                                .process_grpc_event_transaction_with_metrics(
+                                                    EventPretty::Account(account_pretty),
+                                                    bot_wallet,
+                                                )
+                                                .await
+                                            {
+                                                error!("Error processing account event: {e:?}");
+                                            }
                                         }
-                                    }
-                                    Some(UpdateOneof::BlockMeta(sut)) => {
-                                        let block_meta_pretty = factory::create_block_meta_pretty_pooled(sut, created_at);
-                                        log::debug!("Received block meta: {:?}", block_meta_pretty);
-                                        if let Err(e) = event_processor
-                                            .process_grpc_event_transaction_with_metrics(
-                                                EventPretty::BlockMeta(block_meta_pretty),
-                                                bot_wallet,
-                                            )
-                                            .await
-                                        {
-                                            error!("Error processing block meta event: {e:?}");
+                                        Some(UpdateOneof::BlockMeta(sut)) => {
+                                            last_slot = last_slot.max(Some(sut.slot));
+                                            let block_meta_pretty = factory::create_block_meta_pretty_pooled(sut, created_at);
+                                            log::debug!("Received block meta: {:?}", block_meta_pretty);
+                                            if let Err(e) = event_processor
+                                                .process_grpc_event_transaction_with_metrics(
+                                                    EventPretty::BlockMeta(block_meta_pretty),
+                                                    bot_wallet,
+                                                )
+                                                .await
+                                            {
+                                                error!("Error processing block meta event: {e:?}");
+                                            }
                                         }
-                                    }
-                                    Some(UpdateOneof::Transaction(sut)) => {
-                                        let transaction_pretty = factory::create_transaction_pretty_pooled(sut, created_at);
-                                        log::debug!(
-                                            "Received transaction: {} at slot {}",
-                                            transaction_pretty.signature,
-                                            transaction_pretty.slot
-                                        );
-                                        if let Err(e) = event_processor
-                                            .process_grpc_event_transaction_with_metrics(
-                                                EventPretty::Transaction(transaction_pretty),
-                                                bot_wallet,
-                                            )
-                                            .await
-                                        {
-                                            error!("Error processing transaction event: {e:?}");
+                                        Some(UpdateOneof::Transaction(sut)) => {
+                                            last_slot = last_slot.max(Some(sut.slot));
+                                            let transaction_pretty = factory::create_transaction_pretty_pooled(sut, created_at);
+                                            log::debug!(
+                                                "Received transaction: {} at slot {}",
+                                                transaction_pretty.signature,
+                                                transaction_pretty.slot
+                                            );
+                                            let (slot, signature) =
+                                                (transaction_pretty.slot, transaction_pretty.signature);
+                                            if let Some(resumed_slot) = replay_until_slot {
+                                                if slot > resumed_slot {
+                                                    replay_until_slot = None;
+                                                } else if delivered_signatures.contains(&signature) {
+                                                    log::debug!("Skipping replayed transaction: {}", signature);
+                                                    continue;
+                                                }
+                                            }
+                                            if connection_config.resume_from_slot {
+                                                if delivered_slot < Some(slot) {
+                                                    delivered_slot = Some(slot);
+                                                    delivered_signatures.clear();
+                                                }
+                                                if delivered_slot == Some(slot) {
+                                                    delivered_signatures.insert(signature);
+                                                }
+                                            }
+                                            if let Err(e) = event_processor
+                                                .process_grpc_event_transaction_with_metrics(
+                                                    EventPretty::Transaction(transaction_pretty),
+                                                    bot_wallet,
+                                                )
+                                                .await
+                                            {
+                                                error!("Error processing transaction event: {e:?}");
+                                            }
                                         }
-                                    }
-                                    Some(UpdateOneof::Ping(_)) => {
-                                        // 只在需要时获取锁，并立即释放
-                                        if let Ok(mut tx_guard) = subscribe_tx.try_lock() {
-                                            let _ = tx_guard
-                                                .send(SubscribeRequest {
-                                                    ping: Some(SubscribeRequestPing { id: 1 }),
-                                                    ..Default::default()
-                                                })
-                                                .await;
+                                        Some(UpdateOneof::Ping(_)) => {
+                                            // 只在需要时获取锁，并立即释放
+                                            if let Ok(mut tx_guard) = subscribe_tx.try_lock() {
+                                                let _ = tx_guard
+                                                    .send(SubscribeRequest {
+                                                        ping: Some(SubscribeRequestPing { id: 1 }),
+                                                        ..Default::default()
+                                                    })
+                                                    .await;
+                                            }
+                                            log::debug!("service is ping: {}", Local::now());
+                                        }
+                                        Some(UpdateOneof::Pong(_)) => {
+                                            log::debug!("service is pong: {}", Local::now());
+                                        }
+                                        _ => {
+                                            log::debug!("Received other message type");
                                         }
-                                        log::debug!("service is ping: {}", Local::now());
-                                    }
-                                    Some(UpdateOneof::Pong(_)) => {
-                                        log::debug!("service is pong: {}", Local::now());
-                                    }
-                                    _ => {
-                                        log::debug!("Received other message type");
                                     }
                                 }
+                                Some(Err(error)) => {
+                                    error!("Stream error: {error:?}");
+                                    last_error_code = Some(error.code());
+                                    break;
+                                }
+                                None => break,
                             }
-                            Some(Err(error)) => {
-                                error!("Stream error: {error:?}");
+                        }
+                        Some(update) = control_rx.next() => {
+                            if let Err(e) = subscribe_tx.lock().await.send(update).await {
+                                error!("Failed to send subscription update: {}", e);
                                 break;
                             }
-                            None => break,
+                        }
+                        _ = async {
+                            match ping_interval.as_mut() {
+                                Some(interval) => interval.tick().await,
+                                None => std::future::pending().await,
+                            }
+                        } => {
+                            if let Some(period) = ping_period {
+                                if last_message_at.elapsed() > period * MAX_MISSED_PINGS {
+                                    error!(
+                                        "No message received for {:?}, gRPC stream considered dead",
+                                        last_message_at.elapsed()
+                                    );
+                                    break;
+                                }
+                            }
+                            ping_id = ping_id.wrapping_add(1);
+                            let ping = SubscribeRequest {
+                                ping: Some(SubscribeRequestPing { id: ping_id }),
+                                ..Default::default()
+                            };
+                            if let Err(e) = subscribe_tx.lock().await.send(ping).await {
+                                error!("Failed to send ping: {}", e);
+                                break;
+                            }
+                            log::debug!("client ping {}: {}", ping_id, Local::now());
                         }
                     }
-                    Some(update) = control_rx.next() => {
-                        if let Err(e) = subscribe_tx.lock().await.send(update).await {
-                            error!("Failed to send subscription update: {}", e);
-                            break;
+                }
+
+                if !connection_config.auto_reconnect {
+                    break;
+                }
+                // 仅当服务端明确拒绝 from_slot 时放弃续订，其他错误仍从同一 slot 续订
+                if resumed
+                    && matches!(
+                        last_error_code,
+                        Some(tonic::Code::InvalidArgument | tonic::Code::OutOfRange)
+                    )
+                {
+                    resume_rejected = true;
+                }
+                // 断线重连，使用最新的订阅请求（包含 update_subscription 的修改）
+                loop {
+                    tokio::time::sleep(Duration::from_millis(connection_config.reconnect_delay_ms))
+                        .await;
+                    let Some(mut request) = current_request.read().await.clone() else {
+                        // 订阅已被 stop()，不再重连
+                        break 'subscription;
+                    };
+                    if connection_config.resume_from_slot && !resume_rejected {
+                        request.from_slot = last_slot;
+                    } else if resume_rejected {
+                        log::warn!(
+                            "Resubscribing without from_slot, events after slot {:?} are not replayed",
+                            last_slot
+                        );
+                    }
+                    let from_slot = request.from_slot;
+                    match subscription_manager.subscribe(request).await {
+                        Ok((new_subscribe_tx, new_stream)) => {
+                            log::info!("Resubscribed to gRPC stream, from_slot: {:?}", from_slot);
+                            *subscribe_tx.lock().await = new_subscribe_tx;
+                            stream = new_stream;
+                            last_message_at = tokio::time::Instant::now();
+                            resumed = from_slot.is_some();
+                            replay_until_slot = from_slot;
+                            last_error_code = None;
+                            resume_rejected = false;
+                            continue 'subscription;
+                        }
+                        Err(e) => {
+                            error!("Failed to reconnect gRPC stream: {e:?}");
                         }
                     }
                 }